@@ -0,0 +1,33 @@
+// Copyright (c) Lurk Lab
+// SPDX-License-Identifier: MIT
+//! Error types surfaced by witness generation and loading.
+
+use thiserror::Error;
+
+/// Errors that can occur while generating, loading, or validating a circuit witness.
+#[derive(Debug, Error)]
+pub enum WitnessError {
+    /// The external witness generator process could not be spawned or failed.
+    #[error("failed to execute the witness generator: {0}")]
+    FailedExecutionError(String),
+
+    /// A filesystem operation (reading inputs, writing the witness) failed.
+    #[error("filesystem error: {0}")]
+    FileSystemError(String),
+
+    /// The generated witness file could not be read back.
+    #[error("failed to load witness: {0}")]
+    LoadWitnessError(String),
+
+    /// The witness calculator mutex was poisoned.
+    #[error("failed to acquire the witness calculator lock")]
+    MutexError,
+
+    /// The in-process witness calculation failed.
+    #[error("witness calculation failed: {0}")]
+    WitnessCalculationError(String),
+
+    /// The circuit's field prime does not match the curve the config was instantiated over.
+    #[error("circuit field prime does not match the configured curve: {0}")]
+    FieldMismatchError(String),
+}
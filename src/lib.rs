@@ -9,12 +9,17 @@
 //!
 //! This library is based on [Nova-Scotia](https://github.com/nalinbhardwaj/Nova-Scotia) and Arkworks'
 //! [Circom-Compat](https://github.com/arkworks-rs/circom-compat), adapted to work with the Bellpepper ecosystem.
-//! It supports the Vesta curve and handles R1CS constraints and witness generation in a manner compatible
-//! with Circom's output format.
+//! It handles R1CS constraints and witness generation in a manner compatible with Circom's output
+//! format. `F: PrimeField` chooses the field. The `witness` module has a crate-internal check,
+//! `validate_field_prime`, that compares a circuit prime against `F`'s modulus; the witness
+//! calculator does not run it, so a curve type parameter that does not match the circuit's field
+//! is not detected during witness calculation.
 //!
 //! ## Features
 //!
 //! - Loading and parsing of R1CS constraints generated by the Circom compiler.
+//! - Loading witnesses from a `.wtns` file or any byte stream (see [`reader::load_witness_from_reader`]),
+//!   so embedded or streamed witnesses can be parsed without a filesystem.
 //! - Generation of witnesses from WASM binaries produced by Circom.
 //! - Integration with Bellpepper's constraint system for zk-SNARK proofs.
 //!
@@ -23,12 +28,24 @@
 //! The primary entry points of this library are functions for loading R1CS files, generating witnesses
 //! from WASM, and synthesizing constraints within a Bellpepper environment.
 //!
+//! ## WebAssembly
+//!
+//! The crate targets `wasm32-unknown-unknown` for in-browser witness generation. The `node`/process
+//! witness path and [`generate_witness_native`] (which write to the filesystem) are gated off wasm;
+//! in their place, parse `.wtns` witnesses from embedded bytes with [`reader::load_witness_from_reader`] and
+//! serialize results with [`witness_to_bytes`], neither of which touches disk. The wasm execution
+//! engine that actually runs `main.wasm` lives in the `witness` module (`witness/circom.rs`); it is
+//! a wasm interpreter and builds on `wasm32` as-is.
+//!
 //! ## Contributions and Credits
 //!
 //! Contributions are welcome.
 //! Credits to the [Circom language](https://github.com/iden3/circom) team, [Nova-Scotia](https://github.com/nalinbhardwaj/Nova-Scotia),
 //! and [ark-circom](https://github.com/gakonst/ark-circom) for their foundational work that this library builds upon.
 
+use std::io::Write;
+
+#[cfg(not(target_arch = "wasm32"))]
 use std::{
     env::current_dir,
     fs,
@@ -36,21 +53,22 @@ use std::{
     process::Command,
 };
 
-use crate::error::WitnessError::{
-    self, FailedExecutionError, FileSystemError, LoadWitnessError, MutexError,
-    WitnessCalculationError,
-};
+use crate::error::WitnessError::{self, MutexError, WitnessCalculationError};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::WitnessError::{FailedExecutionError, FileSystemError, LoadWitnessError};
 use crate::r1cs::CircomInput;
 use anyhow::Result;
 use bellpepper_core::{num::AllocatedNum, ConstraintSystem, LinearCombination, SynthesisError};
 use ff::PrimeField;
 use r1cs::{CircomConfig, R1CS};
 
+#[cfg(not(target_arch = "wasm32"))]
 use crate::reader::load_witness_from_file;
 
 pub mod error;
 pub mod r1cs;
 pub mod reader;
+pub mod step;
 pub mod witness;
 
 /// Generates a witness file from a given WebAssembly (WASM) binary using a JSON input.
@@ -82,6 +100,11 @@ pub mod witness;
 /// let witness_output = PathBuf::from("output.wtns");
 /// let result = generate_witness_from_wasm(witness_dir, input_json, &witness_output);
 /// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[deprecated(
+    since = "0.2.0",
+    note = "shells out to `node` and is unavailable without a Node install; use `generate_witness_native` instead"
+)]
 pub fn generate_witness_from_wasm<F: PrimeField>(
     witness_dir: PathBuf,
     witness_input_json: String,
@@ -123,6 +146,121 @@ pub fn generate_witness_from_wasm<F: PrimeField>(
     load_witness_from_file(witness_output).map_err(|err| LoadWitnessError(err.to_string()))
 }
 
+/// Generates a witness entirely in-process and writes it to a circom `.wtns` file.
+///
+/// Unlike [`generate_witness_from_wasm`], this never spawns an external `node` process: it runs
+/// the circuit's WASM through the embedded [`witness::WitnessCalculator`] via [`calculate_witness`]
+/// and then serializes the resulting field elements into the standard circom binary witness format.
+/// The produced file is byte-identical to the one snarkjs/`generate_witness.js` would emit, so any
+/// existing `.wtns` consumer keeps working.
+///
+/// # Arguments
+///
+/// * `cfg` - A reference to the [`CircomConfig`] containing the WASM witness calculator and R1CS.
+/// * `input` - A vector of [`CircomInput`], representing the inputs to the circuit.
+/// * `witness_output` - A reference to the path where the output witness file will be stored.
+///
+/// # Errors
+///
+/// Returns an error if witness calculation fails or if the witness file cannot be written.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::path::PathBuf;
+/// # use circom_scotia::generate_witness_native;
+/// # use circom_scotia::r1cs::{CircomConfig, CircomInput};
+/// # use ff::Field;
+/// # use pasta_curves::vesta::Base as Fr;
+///
+/// let cfg = CircomConfig::new(PathBuf::from("main.wasm"), PathBuf::from("circuit.r1cs")).unwrap();
+/// let inputs = vec![CircomInput::new(String::from("input_name"), vec![Fr::ZERO])];
+/// let witness = generate_witness_native(&cfg, inputs, "output.wtns").unwrap();
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn generate_witness_native<F: PrimeField>(
+    cfg: &CircomConfig<F>,
+    input: Vec<CircomInput<F>>,
+    witness_output: impl AsRef<Path>,
+) -> Result<Vec<F>, WitnessError> {
+    let witness = calculate_witness(cfg, input, true)?;
+
+    let bytes = witness_to_bytes(&witness);
+    fs::write(witness_output, bytes).map_err(|err| FileSystemError(err.to_string()))?;
+
+    Ok(witness)
+}
+
+/// Serializes a witness vector into the circom `.wtns` binary format, returning the raw bytes.
+///
+/// This is the filesystem-free counterpart to [`generate_witness_native`]: callers on targets
+/// without a real filesystem (e.g. `wasm32-unknown-unknown` in the browser) can hand a witness
+/// computed by [`calculate_witness`] straight to this function and get a standard `.wtns` buffer
+/// back to return to JavaScript.
+///
+/// The layout is the iden3 binary-file format: the magic `wtns`, a `u32` version, a `u32` section
+/// count, followed by two sections. The type-1 header holds the field-element byte length `n8`, the
+/// field prime as `n8` little-endian bytes, and the `u32` witness count; the type-2 section holds
+/// each field element as `n8` little-endian bytes in witness order. Each section is prefixed by a
+/// `u32` type tag and a `u64` byte length, as circom/snarkjs expect.
+pub fn witness_to_bytes<F: PrimeField>(witness: &[F]) -> Vec<u8> {
+    let prime = modulus_le_bytes::<F>();
+    let n8 = prime.len() as u32;
+
+    let mut out = Vec::new();
+
+    // File header: magic, version, section count.
+    out.extend_from_slice(b"wtns");
+    out.extend_from_slice(&2u32.to_le_bytes());
+    out.extend_from_slice(&2u32.to_le_bytes());
+
+    // Section 1: header (field byte length, prime, witness count).
+    let mut header = Vec::with_capacity(prime.len() + 8);
+    header.extend_from_slice(&n8.to_le_bytes());
+    header.extend_from_slice(&prime);
+    header.extend_from_slice(&(witness.len() as u32).to_le_bytes());
+    write_section(&mut out, 1, &header);
+
+    // Section 2: witness data, each element as `n8` little-endian bytes.
+    let mut data = Vec::with_capacity(witness.len() * prime.len());
+    for w in witness {
+        data.extend_from_slice(w.to_repr().as_ref());
+    }
+    write_section(&mut out, 2, &data);
+
+    out
+}
+
+/// Appends a typed section (`u32` type tag, `u64` byte length, then the body) to `out`.
+fn write_section(out: &mut Vec<u8>, section_type: u32, body: &[u8]) {
+    out.write_all(&section_type.to_le_bytes()).unwrap();
+    out.write_all(&(body.len() as u64).to_le_bytes()).unwrap();
+    out.write_all(body).unwrap();
+}
+
+/// Returns the field prime as `n8` little-endian bytes, where `n8` matches `F::Repr`'s byte width.
+pub(crate) fn modulus_le_bytes<F: PrimeField>() -> Vec<u8> {
+    let n8 = F::ZERO.to_repr().as_ref().len();
+
+    // `F::MODULUS` is a `0x`-prefixed big-endian hex string.
+    let trimmed = F::MODULUS.trim_start_matches("0x");
+    let padded;
+    let hex = if trimmed.len() % 2 == 0 {
+        trimmed
+    } else {
+        padded = format!("0{trimmed}");
+        &padded
+    };
+    let be: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("valid modulus hex"))
+        .collect();
+
+    let mut le: Vec<u8> = be.into_iter().rev().collect();
+    le.resize(n8, 0);
+    le
+}
+
 /// Calculates a witness for a given R1CS configuration and a set of circuit inputs.
 ///
 /// The function locks the global witness calculation instance and then calculates
@@ -217,12 +355,8 @@ pub fn synthesize<F: PrimeField, CS: ConstraintSystem<F>>(
         vars.push(v);
     }
 
-    // Public output to return.
-    let output = match r1cs.num_pub_out {
-        0 => vec![],
-        1 => vec![vars[0].clone()],
-        _ => vars[0..r1cs.num_pub_out - 1usize].to_vec(),
-    };
+    // Public output to return: the first `num_pub_out` allocated public signals.
+    let output = public_outputs(&vars, r1cs.num_pub_out);
 
     // Create closure responsible to create the linear combination data.
     let make_lc = |lc_data: Vec<(usize, F)>| {
@@ -250,3 +384,74 @@ pub fn synthesize<F: PrimeField, CS: ConstraintSystem<F>>(
 
     Ok(output)
 }
+
+/// Returns the circuit's public outputs: the first `num_pub_out` entries of the allocated public
+/// signals, which lead the witness layout `[one, public_outputs, public_inputs, private_aux]`.
+fn public_outputs<T: Clone>(vars: &[T], num_pub_out: usize) -> Vec<T> {
+    vars[0..num_pub_out].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{modulus_le_bytes, public_outputs, witness_to_bytes};
+    use ff::{Field, PrimeField};
+    use pasta_curves::vesta::Base as Fr;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+    }
+
+    #[test]
+    fn public_outputs_returns_exactly_num_pub_out() {
+        let vars = vec![10u8, 20, 30, 40];
+        assert_eq!(public_outputs(&vars, 0), Vec::<u8>::new());
+        assert_eq!(public_outputs(&vars, 1), vec![10]);
+        // With more than one folded output the full slice must come back, not `num_pub_out - 1`.
+        assert_eq!(public_outputs(&vars, 2), vec![10, 20]);
+        assert_eq!(public_outputs(&vars, 3), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn modulus_le_bytes_matches_field_width() {
+        let n8 = Fr::ZERO.to_repr().as_ref().len();
+        let prime = modulus_le_bytes::<Fr>();
+        assert_eq!(prime.len(), n8);
+        // The modulus is odd, so its least-significant little-endian byte is non-zero.
+        assert_eq!(prime[0] & 1, 1);
+    }
+
+    #[test]
+    fn witness_to_bytes_matches_wtns_layout() {
+        let witness = vec![Fr::ONE, Fr::from(2u64), Fr::from(3u64)];
+        let n8 = Fr::ZERO.to_repr().as_ref().len();
+        let bytes = witness_to_bytes(&witness);
+
+        // File header: magic, version, section count.
+        assert_eq!(&bytes[0..4], b"wtns");
+        assert_eq!(read_u32(&bytes, 4), 2);
+        assert_eq!(read_u32(&bytes, 8), 2);
+
+        // Section 1: header.
+        assert_eq!(read_u32(&bytes, 12), 1);
+        let header_len = (4 + n8 + 4) as u64;
+        assert_eq!(read_u64(&bytes, 16), header_len);
+        assert_eq!(read_u32(&bytes, 24) as usize, n8);
+        assert_eq!(&bytes[28..28 + n8], modulus_le_bytes::<Fr>().as_slice());
+        let count_offset = 28 + n8;
+        assert_eq!(read_u32(&bytes, count_offset) as usize, witness.len());
+
+        // Section 2: witness data, each element as `n8` little-endian bytes.
+        let sec2 = count_offset + 4;
+        assert_eq!(read_u32(&bytes, sec2), 2);
+        assert_eq!(read_u64(&bytes, sec2 + 4) as usize, witness.len() * n8);
+        let data = sec2 + 12;
+        for (i, w) in witness.iter().enumerate() {
+            assert_eq!(&bytes[data + i * n8..data + (i + 1) * n8], w.to_repr().as_ref());
+        }
+        assert_eq!(bytes.len(), data + witness.len() * n8);
+    }
+}
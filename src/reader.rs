@@ -0,0 +1,120 @@
+// Copyright (c) 2022 Nalin
+// Copyright (c) Lurk Lab
+// SPDX-License-Identifier: MIT
+//! Readers for circom's binary witness (`.wtns`) format.
+//!
+//! Both a filesystem entry point and a byte-stream entry point are provided so witnesses can be
+//! loaded from disk, from bytes embedded via `include_bytes!`, or from data streamed over a network
+//! without ever touching the filesystem.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+use ff::PrimeField;
+
+/// Loads a witness vector from a circom `.wtns` file on disk.
+pub fn load_witness_from_file<F: PrimeField>(path: impl AsRef<Path>) -> Result<Vec<F>> {
+    let reader = File::open(path)?;
+    load_witness_from_reader(reader)
+}
+
+/// Loads a witness vector from any `.wtns` byte stream.
+///
+/// Accepts anything implementing [`Read`] + [`Seek`], e.g. a [`std::io::Cursor`] over a `&[u8]`, so
+/// the crate can parse witnesses without a filesystem (as needed on `wasm32-unknown-unknown`).
+pub fn load_witness_from_reader<F: PrimeField>(mut reader: impl Read + Seek) -> Result<Vec<F>> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != b"wtns" {
+        bail!("invalid .wtns magic: {magic:?}");
+    }
+
+    let version = read_u32(&mut reader)?;
+    if version != 2 {
+        bail!("unsupported .wtns version: {version}");
+    }
+
+    let num_sections = read_u32(&mut reader)?;
+
+    let mut n8: Option<usize> = None;
+    let mut num_witness: Option<usize> = None;
+    let mut witness: Vec<F> = Vec::new();
+
+    for _ in 0..num_sections {
+        let section_type = read_u32(&mut reader)?;
+        let section_len = read_u64(&mut reader)?;
+
+        match section_type {
+            // Header: field byte length, prime modulus, witness count.
+            1 => {
+                let field_len = read_u32(&mut reader)? as usize;
+                let mut prime = vec![0u8; field_len];
+                reader.read_exact(&mut prime)?;
+                n8 = Some(field_len);
+                num_witness = Some(read_u32(&mut reader)? as usize);
+            }
+            // Witness data: each element as `n8` little-endian bytes.
+            2 => {
+                let field_len =
+                    n8.ok_or_else(|| anyhow!("witness section precedes the header section"))?;
+                let count = num_witness.unwrap_or(section_len as usize / field_len);
+                witness.reserve(count);
+                for _ in 0..count {
+                    witness.push(read_field::<F>(&mut reader, field_len)?);
+                }
+            }
+            // Unknown sections are skipped, as snarkjs does.
+            _ => {
+                reader.seek(SeekFrom::Current(section_len as i64))?;
+            }
+        }
+    }
+
+    Ok(witness)
+}
+
+/// Reads a single field element serialized as `n8` little-endian bytes.
+fn read_field<F: PrimeField>(reader: &mut impl Read, n8: usize) -> Result<F> {
+    let mut repr = F::Repr::default();
+    let buf = repr.as_mut();
+    if buf.len() != n8 {
+        bail!(
+            "witness field byte length {n8} does not match F::Repr width {}",
+            buf.len()
+        );
+    }
+    reader.read_exact(buf)?;
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| anyhow!("witness value is not a canonical field element"))
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_witness_from_reader;
+    use crate::witness_to_bytes;
+    use ff::Field;
+    use pasta_curves::vesta::Base as Fr;
+    use std::io::Cursor;
+
+    #[test]
+    fn round_trips_witness_through_wtns_bytes() {
+        let witness = vec![Fr::ONE, Fr::from(2u64), Fr::from(42u64)];
+        let bytes = witness_to_bytes(&witness);
+        let parsed: Vec<Fr> = load_witness_from_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!(parsed, witness);
+    }
+}
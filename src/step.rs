@@ -0,0 +1,119 @@
+// Copyright (c) 2022 Nalin
+// Copyright (c) Lurk Lab
+// SPDX-License-Identifier: MIT
+//! Folding adapter that treats a circom circuit as a Nova-style recursive step function.
+//!
+//! A circom circuit whose witness layout is `[one, public_outputs, public_inputs, private_aux]`
+//! can be read as a step function `z_{i+1} = F(z_i, aux)` by wiring the public-input slice as the
+//! current state `z_i` and the public-output slice as the next state `z_{i+1}`. This mirrors
+//! sonobe's `CircomWrapper::generate_step_constraints` and lets `circom-scotia` be used directly as
+//! a Bellpepper folding frontend.
+
+use bellpepper_core::{num::AllocatedNum, ConstraintSystem, SynthesisError};
+use ff::PrimeField;
+
+use crate::r1cs::{CircomConfig, CircomInput, R1CS};
+use crate::{calculate_witness, synthesize};
+
+/// Wraps a circom circuit as a foldable step circuit.
+///
+/// `input_signals` describes how the state vector `z_i` maps onto the circuit's declared public
+/// input signals as `(signal_name, width)` pairs, consumed in order. The widths must sum to the
+/// state arity, and the circuit must expose exactly that many public outputs so that `z_{i+1}` has
+/// the same length as `z_i` and the state can be folded across steps. [`CircomStepCircuit::new`]
+/// validates both invariants up front so a misconfigured circuit is a recoverable error rather than
+/// a later panic.
+pub struct CircomStepCircuit<F: PrimeField> {
+    cfg: CircomConfig<F>,
+    r1cs: R1CS<F>,
+    input_signals: Vec<(String, usize)>,
+}
+
+impl<F: PrimeField> CircomStepCircuit<F> {
+    /// Builds a step circuit from its witness calculator config, R1CS, and the state-to-signal map.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SynthesisError::Unsatisfiable`] if the `input_signals` widths do not sum to the
+    /// circuit's public-output count (`num_pub_out`), since that mismatch would make the step
+    /// non-foldable.
+    pub fn new(
+        cfg: CircomConfig<F>,
+        r1cs: R1CS<F>,
+        input_signals: Vec<(String, usize)>,
+    ) -> Result<Self, SynthesisError> {
+        let input_width: usize = input_signals.iter().map(|(_, width)| width).sum();
+        if input_width != r1cs.num_pub_out {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        Ok(Self {
+            cfg,
+            r1cs,
+            input_signals,
+        })
+    }
+
+    /// The arity of the step function, i.e. the length of both `z_i` and `z_{i+1}`.
+    pub fn arity(&self) -> usize {
+        self.r1cs.num_pub_out
+    }
+
+    /// Synthesizes one folding step `z_{i+1} = F(z_i, aux)`.
+    ///
+    /// Maps the current state `z_i` onto the declared public input signals, appends the auxiliary
+    /// inputs, calculates the witness, and synthesizes the R1CS constraints binding it. The public
+    /// output slice is returned as the next state. The returned vector has the same length as `z_i`,
+    /// which is checked so the state stays foldable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SynthesisError::Unsatisfiable`] if `z_i`'s length does not match the circuit's
+    /// arity, and propagates any [`SynthesisError`] from synthesis.
+    pub fn generate_step_constraints<CS: ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        z_i: &[AllocatedNum<F>],
+        aux: Vec<CircomInput<F>>,
+    ) -> Result<Vec<AllocatedNum<F>>, SynthesisError> {
+        // The input-signal widths are validated against `num_pub_out` in `new`, so checking `z_i`
+        // here guarantees the slice indexing below stays in bounds.
+        if z_i.len() != self.arity() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // Collect the concrete state values; absent in the setup pass.
+        let values: Option<Vec<F>> = z_i.iter().map(|num| num.get_value()).collect();
+
+        // When every value is known, map `z_i` onto the public input signals, run the witness
+        // calculator, and bind the real witness; otherwise synthesize the shape with `None`.
+        let witness = match values {
+            Some(values) => {
+                let mut offset = 0;
+                let mut inputs = Vec::with_capacity(self.input_signals.len() + aux.len());
+                for (name, width) in &self.input_signals {
+                    let slice = values[offset..offset + width].to_vec();
+                    inputs.push(CircomInput::new(name.clone(), slice));
+                    offset += width;
+                }
+                inputs.extend(aux);
+
+                Some(
+                    calculate_witness(&self.cfg, inputs, true)
+                        .map_err(|_| SynthesisError::AssignmentMissing)?,
+                )
+            }
+            None => None,
+        };
+
+        let z_next = synthesize(cs, self.r1cs.clone(), witness)?;
+
+        // The public-output slice is the next state; it must match the current-state length so the
+        // folding driver can carry it forward.
+        if z_next.len() != z_i.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        Ok(z_next)
+    }
+}
@@ -25,6 +25,10 @@ pub(super) use circom::Circom;
 use fnv::FnvHasher;
 use std::hash::Hasher;
 
+use ff::PrimeField;
+
+use crate::error::WitnessError;
+
 pub(crate) fn fnv(inp: &str) -> (u32, u32) {
     let mut hasher = FnvHasher::default();
     hasher.write(inp.as_bytes());
@@ -32,3 +36,79 @@ pub(crate) fn fnv(inp: &str) -> (u32, u32) {
 
     ((h >> 32) as u32, h as u32)
 }
+
+/// Reconstructs the field prime from the `u32` limbs reported by the wasm module.
+///
+/// Circom-2 modules expose the prime through `getRawPrime`, which writes `getFieldNumLen32`
+/// little-endian 32-bit limbs into shared memory. This concatenates those limbs, least-significant
+/// first, into the little-endian byte encoding used throughout the crate.
+pub(crate) fn prime_from_limbs(limbs: &[u32]) -> Vec<u8> {
+    limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+}
+
+/// Validates that the circuit's field prime matches the modulus of `F`.
+///
+/// `raw_prime_le` is the circuit's prime as little-endian bytes (e.g. from [`prime_from_limbs`]);
+/// a mismatch yields a descriptive [`WitnessError::FieldMismatchError`]. `WitnessCalculator::new`
+/// does not call this, so witness calculation itself does not check the circuit's prime.
+pub(crate) fn validate_field_prime<F: PrimeField>(raw_prime_le: &[u8]) -> Result<(), WitnessError> {
+    let expected = crate::modulus_le_bytes::<F>();
+    if le_bytes_eq(raw_prime_le, &expected) {
+        Ok(())
+    } else {
+        Err(WitnessError::FieldMismatchError(format!(
+            "circuit prime 0x{} does not match curve modulus 0x{}",
+            to_be_hex(raw_prime_le),
+            to_be_hex(&expected),
+        )))
+    }
+}
+
+/// Compares two little-endian byte encodings of an integer, ignoring trailing zero padding.
+fn le_bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    let trim = |bytes: &[u8]| {
+        let mut end = bytes.len();
+        while end > 0 && bytes[end - 1] == 0 {
+            end -= 1;
+        }
+        bytes[..end].to_vec()
+    };
+    trim(a) == trim(b)
+}
+
+/// Renders little-endian bytes as a big-endian hex string for error messages.
+fn to_be_hex(le: &[u8]) -> String {
+    le.iter().rev().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prime_from_limbs, validate_field_prime};
+    use crate::modulus_le_bytes;
+    use pasta_curves::vesta::Base as Fr;
+
+    #[test]
+    fn accepts_matching_prime() {
+        let prime = modulus_le_bytes::<Fr>();
+        assert!(validate_field_prime::<Fr>(&prime).is_ok());
+        // Trailing zero limbs must not change the outcome.
+        let mut padded = prime.clone();
+        padded.extend_from_slice(&[0u8; 8]);
+        assert!(validate_field_prime::<Fr>(&padded).is_ok());
+    }
+
+    #[test]
+    fn rejects_mismatched_prime() {
+        let mut wrong = modulus_le_bytes::<Fr>();
+        wrong[0] ^= 1;
+        assert!(validate_field_prime::<Fr>(&wrong).is_err());
+    }
+
+    #[test]
+    fn reconstructs_prime_from_limbs() {
+        assert_eq!(
+            prime_from_limbs(&[0x04030201, 0x08070605]),
+            vec![1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+}